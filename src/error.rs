@@ -0,0 +1,17 @@
+use thiserror::Error as ThisError;
+
+/// Errors produced by [`crate::OtlpLogsExporter`].
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("invalid endpoint: {0}")]
+    InvalidUri(#[from] tonic::codegen::http::uri::InvalidUri),
+
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    #[error("grpc status: {0}")]
+    Status(#[from] tonic::Status),
+
+    #[error("collector rejected {rejected} log record(s): {message}")]
+    PartialSuccess { rejected: i64, message: String },
+}
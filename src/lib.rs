@@ -1,6 +1,7 @@
 use opentelemetry_proto::{
     tonic::collector::logs::v1::{
-        ExportLogsServiceRequest, logs_service_client::LogsServiceClient,
+        ExportLogsPartialSuccess, ExportLogsServiceRequest, ExportLogsServiceResponse,
+        logs_service_client::LogsServiceClient,
     },
     transform::{
         common::tonic::ResourceAttributesWithSchema, logs::tonic::group_logs_by_resource_and_scope,
@@ -12,17 +13,32 @@ use error::Error;
 pub use opentelemetry_proto;
 
 pub mod retry;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::time::Duration;
+
 use opentelemetry_sdk::{
     Resource,
     error::{OTelSdkError, OTelSdkResult},
     logs::{LogBatch, LogExporter},
 };
-use retry::RetryPolicy;
+use retry::{DefaultRetryClassifier, RetryClassifier, RetryPolicy};
 use tokio::sync::Mutex;
 use tonic::{codec::CompressionEncoding, transport::Channel};
 
 use crate::retry::export_with_retry;
 
+/// Receives OTLP `partial_success` responses from the collector.
+///
+/// A collector can return `200 OK` while still rejecting some log records,
+/// reporting this via `ExportLogsServiceResponse.partial_success`. Register
+/// a handler via [`OtlpLogsExporter::with_partial_success_handler`] to
+/// observe those rejections; if none is registered, a non-empty partial
+/// success is surfaced as [`Error::PartialSuccess`] instead.
+pub trait PartialSuccessHandler: std::fmt::Debug + Send + Sync {
+    fn handle(&self, partial_success: ExportLogsPartialSuccess);
+}
+
 /// OtlpLogsExporter is a log exporter for OpenTelemetry that uses Tonic to send
 /// logs to a collector.
 ///
@@ -33,8 +49,22 @@ pub struct OtlpLogsExporter {
     client: Mutex<LogsServiceClient<Channel>>,
     retry_policy: RetryPolicy,
     resource: ResourceAttributesWithSchema,
+    classifier: Arc<dyn RetryClassifier>,
+    retry_tokens: AtomicUsize,
+    partial_success_handler: Option<Arc<dyn PartialSuccessHandler>>,
+    endpoint: Option<Arc<str>>,
+    /// Single-flights channel rebuilds: held for the duration of a
+    /// reconnect attempt so that concurrent callers observing the same
+    /// broken connection don't each spin up their own reconnect loop
+    /// against the same endpoint.
+    reconnecting: Mutex<()>,
 }
 
+/// Bounded backoff used when rebuilding a broken channel. Kept separate from
+/// [`RetryPolicy`] since reconnects are a distinct, much rarer failure mode
+/// than per-request retries.
+const RECONNECT_DELAYS_MS: [u64; 3] = [100, 500, 2000];
+
 impl OtlpLogsExporter {
     pub async fn with_default_retry(endpoint: &str) -> Result<Self, Error> {
         let retry_policy = RetryPolicy {
@@ -42,6 +72,7 @@ impl OtlpLogsExporter {
             initial_delay_ms: 100,
             max_delay_ms: 1600,
             jitter_ms: 100,
+            retry_tokens: 500,
         };
 
         Self::new(endpoint, retry_policy).await
@@ -51,18 +82,141 @@ impl OtlpLogsExporter {
         let client = LogsServiceClient::new(channel)
             .send_compressed(CompressionEncoding::Zstd)
             .accept_compressed(CompressionEncoding::Zstd);
+        let retry_tokens = AtomicUsize::new(retry_policy.retry_tokens);
 
         Self {
             retry_policy,
             client: Mutex::new(client),
             resource: Default::default(),
+            classifier: Arc::new(DefaultRetryClassifier),
+            retry_tokens,
+            partial_success_handler: None,
+            endpoint: None,
+            reconnecting: Mutex::new(()),
         }
     }
 
+    /// Connects to `endpoint` and enables automatic reconnection: if the
+    /// channel goes unavailable (e.g. the collector restarts), it is rebuilt
+    /// from this same endpoint when an export fails with `Unavailable`, so
+    /// later exports use the new channel.
+    ///
+    /// Exporters built via [`Self::with_channel`] don't reconnect, since they
+    /// are handed an already-built `Channel` with no endpoint to rebuild
+    /// from.
     pub async fn new(endpoint: &str, retry_policy: RetryPolicy) -> Result<Self, Error> {
         let channel_builder = Channel::from_shared(endpoint.to_string())?;
         let channel = channel_builder.connect().await?;
-        Ok(Self::with_channel(channel, retry_policy))
+        let mut exporter = Self::with_channel(channel, retry_policy);
+        exporter.endpoint = Some(endpoint.into());
+        Ok(exporter)
+    }
+
+    /// Overrides the classifier used to decide whether a failed export
+    /// should be retried. Defaults to [`retry::DefaultRetryClassifier`].
+    ///
+    /// Pass a [`retry::ChainRetryClassifier`] to layer a custom classifier on
+    /// top of the default instead of replacing it outright.
+    pub fn with_retry_classifier(mut self, classifier: Arc<dyn RetryClassifier>) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Registers a handler for OTLP `partial_success` responses. See
+    /// [`PartialSuccessHandler`].
+    pub fn with_partial_success_handler(mut self, handler: Arc<dyn PartialSuccessHandler>) -> Self {
+        self.partial_success_handler = Some(handler);
+        self
+    }
+
+    /// Surfaces a non-empty `partial_success` via the registered
+    /// [`PartialSuccessHandler`], or as [`Error::PartialSuccess`] if none is
+    /// registered.
+    fn handle_partial_success(
+        &self,
+        partial_success: Option<ExportLogsPartialSuccess>,
+    ) -> Result<(), Error> {
+        let Some(partial_success) = partial_success else {
+            return Ok(());
+        };
+        if partial_success.rejected_log_records == 0 {
+            return Ok(());
+        }
+
+        match &self.partial_success_handler {
+            Some(handler) => {
+                handler.handle(partial_success);
+                Ok(())
+            }
+            None => Err(Error::PartialSuccess {
+                rejected: partial_success.rejected_log_records,
+                message: partial_success.error_message,
+            }),
+        }
+    }
+
+    /// Rebuilds the gRPC channel from the endpoint this exporter was
+    /// constructed with, with its own bounded backoff. A no-op if the
+    /// exporter has no endpoint to reconnect to (see [`Self::new`]).
+    ///
+    /// Single-flighted via `reconnecting`: if another caller is already
+    /// rebuilding the channel, this returns immediately instead of racing
+    /// it with a redundant reconnect attempt against the same endpoint.
+    async fn reconnect(&self) {
+        let Some(endpoint) = &self.endpoint else {
+            return;
+        };
+
+        let Ok(_guard) = self.reconnecting.try_lock() else {
+            return;
+        };
+
+        for delay_ms in RECONNECT_DELAYS_MS {
+            let Ok(builder) = Channel::from_shared(endpoint.to_string()) else {
+                return;
+            };
+
+            match builder.connect().await {
+                Ok(channel) => {
+                    *self.client.lock().await = LogsServiceClient::new(channel)
+                        .send_compressed(CompressionEncoding::Zstd)
+                        .accept_compressed(CompressionEncoding::Zstd);
+                    return;
+                }
+                Err(_) => tokio::time::sleep(Duration::from_millis(delay_ms)).await,
+            }
+        }
+    }
+
+    /// Sends `request`, retrying according to the exporter's retry policy.
+    ///
+    /// If retrying stops with an `Unavailable` status as the final error, the
+    /// channel is reconnected so the next export attempt starts from a fresh
+    /// connection; this attempt still reports the error. Other status codes
+    /// do not trigger a reconnect.
+    async fn export_with_reconnect(
+        &self,
+        request: &ExportLogsServiceRequest,
+    ) -> Result<ExportLogsServiceResponse, Error> {
+        let result = {
+            let mut client = self.client.lock().await;
+            export_with_retry(
+                &mut client,
+                &self.retry_policy,
+                &self.classifier,
+                &self.retry_tokens,
+                request,
+            )
+            .await
+        };
+
+        if let Err(Error::Status(status)) = &result {
+            if status.code() == tonic::Code::Unavailable {
+                self.reconnect().await;
+            }
+        }
+
+        result
     }
 
     /// Export a single logs request.
@@ -70,8 +224,8 @@ impl OtlpLogsExporter {
     /// This function will retry if the request fails based on the exporter's
     /// retry policy.
     pub async fn send_request(&mut self, request: ExportLogsServiceRequest) -> Result<(), Error> {
-        let mut client = self.client.lock().await;
-        export_with_retry(&mut client, &self.retry_policy, &request).await
+        let response = self.export_with_reconnect(&request).await?;
+        self.handle_partial_success(response.partial_success)
     }
 }
 
@@ -80,14 +234,12 @@ impl LogExporter for OtlpLogsExporter {
         let resource_logs = group_logs_by_resource_and_scope(batch, &self.resource);
         let request = ExportLogsServiceRequest { resource_logs };
 
-        let mut client = self.client.lock().await;
+        let result = self
+            .export_with_reconnect(&request)
+            .await
+            .and_then(|response| self.handle_partial_success(response.partial_success));
 
-        match export_with_retry(&mut client, &self.retry_policy, &request).await {
-            Ok(_) => Ok(()),
-            Err(error) => Err(OTelSdkError::InternalFailure(format!(
-                "OTLP export error: {error:?}"
-            ))),
-        }
+        result.map_err(|error| OTelSdkError::InternalFailure(format!("OTLP export error: {error:?}")))
     }
 
     fn set_resource(&mut self, resource: &Resource) {
@@ -97,9 +249,103 @@ impl LogExporter for OtlpLogsExporter {
 
 #[cfg(test)]
 mod tests {
-    use crate::retry::{RetryErrorType, classify_tonic_status};
+    use crate::retry::{RetryErrorType, RetryPolicy, classify_tonic_status};
     use tonic::Status;
 
+    use super::*;
+
+    fn test_exporter() -> OtlpLogsExporter {
+        let channel = Channel::from_shared("http://localhost:4317")
+            .unwrap()
+            .connect_lazy();
+
+        OtlpLogsExporter::with_channel(
+            channel,
+            RetryPolicy {
+                max_retries: 3,
+                initial_delay_ms: 100,
+                max_delay_ms: 1600,
+                jitter_ms: 100,
+                retry_tokens: 500,
+            },
+        )
+    }
+
+    #[test]
+    fn test_handle_partial_success_without_rejection_is_ok() {
+        let exporter = test_exporter();
+
+        assert!(exporter.handle_partial_success(None).is_ok());
+        assert!(
+            exporter
+                .handle_partial_success(Some(ExportLogsPartialSuccess {
+                    rejected_log_records: 0,
+                    error_message: String::new(),
+                }))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_handle_partial_success_without_handler_errors() {
+        let exporter = test_exporter();
+
+        let result = exporter.handle_partial_success(Some(ExportLogsPartialSuccess {
+            rejected_log_records: 3,
+            error_message: "rejected".to_string(),
+        }));
+
+        assert!(matches!(
+            result,
+            Err(Error::PartialSuccess { rejected: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_handle_partial_success_with_handler_delegates() {
+        #[derive(Debug)]
+        struct RecordingHandler {
+            seen: std::sync::Mutex<Option<ExportLogsPartialSuccess>>,
+        }
+
+        impl PartialSuccessHandler for RecordingHandler {
+            fn handle(&self, partial_success: ExportLogsPartialSuccess) {
+                *self.seen.lock().unwrap() = Some(partial_success);
+            }
+        }
+
+        let handler = Arc::new(RecordingHandler {
+            seen: std::sync::Mutex::new(None),
+        });
+        let exporter = test_exporter().with_partial_success_handler(handler.clone());
+
+        let result = exporter.handle_partial_success(Some(ExportLogsPartialSuccess {
+            rejected_log_records: 2,
+            error_message: "oops".to_string(),
+        }));
+
+        assert!(result.is_ok());
+        assert_eq!(
+            handler
+                .seen
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .rejected_log_records,
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_is_noop_without_endpoint() {
+        // Built via `with_channel`, so `endpoint` is `None`; `reconnect` must
+        // return immediately rather than trying to rebuild a channel, which
+        // would require a live collector to connect to.
+        let exporter = test_exporter();
+        exporter.reconnect().await;
+    }
+
     #[test]
     fn test_classify_unavailable_error() {
         let status = Status::unavailable("Service unavailable");
@@ -0,0 +1,325 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use opentelemetry_proto::tonic::collector::logs::v1::{
+    ExportLogsServiceRequest, ExportLogsServiceResponse, logs_service_client::LogsServiceClient,
+};
+use rand::Rng;
+use tonic::{Status, transport::Channel};
+use tonic_types::StatusExt;
+
+use crate::error::Error;
+
+/// The retry policy used by [`crate::OtlpLogsExporter`] when a request to
+/// the collector fails.
+///
+/// Delays between attempts grow exponentially from `initial_delay_ms`,
+/// capped at `max_delay_ms`, with up to `jitter_ms` of random jitter added
+/// to avoid thundering-herd retries.
+///
+/// `retry_tokens` bounds how many retries the exporter as a whole can make
+/// between successful requests: each retry attempt withdraws from a shared token
+/// bucket of this capacity, and a successful request refills it slightly, so
+/// a sustained collector outage can't make every failed batch retry at full
+/// strength simultaneously.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub jitter_ms: u64,
+    pub retry_tokens: usize,
+}
+
+/// Classification of a gRPC error for the purposes of the retry loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryErrorType {
+    /// The request can be retried using the policy's own backoff.
+    Retryable,
+    /// The request must not be retried.
+    NonRetryable,
+    /// The request can be retried, but the collector asked for a specific
+    /// delay via `google.rpc.RetryInfo`; this overrides the policy's
+    /// computed backoff.
+    RetryAfter(Duration),
+}
+
+impl Default for RetryErrorType {
+    /// The conservative default is to not retry; a classifier must
+    /// explicitly opt an error into `Retryable`.
+    fn default() -> Self {
+        RetryErrorType::NonRetryable
+    }
+}
+
+/// Decides whether a failed export should be retried.
+///
+/// Implement this trait to customize how `tonic::Status` codes returned by a
+/// collector are mapped to a [`RetryErrorType`]. This is useful when a
+/// collector does not follow the standard OTLP/gRPC status code conventions
+/// (e.g. it returns `Internal` for a condition that is actually transient).
+pub trait RetryClassifier: std::fmt::Debug + Send + Sync {
+    /// Classify a `tonic::Status` returned by the collector.
+    fn classify(&self, status: &Status) -> RetryErrorType;
+}
+
+/// The classifier used by [`crate::OtlpLogsExporter`] unless a different one
+/// is configured. Mirrors the standard OTLP/gRPC retry guidance.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRetryClassifier;
+
+impl RetryClassifier for DefaultRetryClassifier {
+    fn classify(&self, status: &Status) -> RetryErrorType {
+        classify_tonic_status(status)
+    }
+}
+
+/// An ordered chain of [`RetryClassifier`]s.
+///
+/// Classifiers are consulted in order; the first one to return a verdict
+/// other than [`RetryErrorType::default`] wins. This lets a user layer a
+/// narrow, special-case classifier on top of [`DefaultRetryClassifier`]:
+/// place the custom classifier first so it can make specific codes
+/// retryable, and the default last as the common-case fallback. Since
+/// `NonRetryable` is the default verdict, it never stops the chain, so a
+/// classifier cannot make a code non-retryable that a later one retries.
+#[derive(Debug, Clone)]
+pub struct ChainRetryClassifier {
+    classifiers: Vec<Arc<dyn RetryClassifier>>,
+}
+
+impl ChainRetryClassifier {
+    pub fn new(classifiers: Vec<Arc<dyn RetryClassifier>>) -> Self {
+        Self { classifiers }
+    }
+}
+
+impl RetryClassifier for ChainRetryClassifier {
+    fn classify(&self, status: &Status) -> RetryErrorType {
+        for classifier in &self.classifiers {
+            let verdict = classifier.classify(status);
+            if verdict != RetryErrorType::default() {
+                return verdict;
+            }
+        }
+        RetryErrorType::default()
+    }
+}
+
+/// Maps a `tonic::Status` returned by a collector to a [`RetryErrorType`]
+/// using the standard OTLP/gRPC retry guidance.
+///
+/// If the status carries a `google.rpc.RetryInfo` detail with a
+/// `retry_delay` (the OTLP/gRPC throttling signal), the status is always
+/// treated as retryable and the server-specified delay is returned via
+/// [`RetryErrorType::RetryAfter`], taking precedence over the status code
+/// itself.
+pub fn classify_tonic_status(status: &Status) -> RetryErrorType {
+    use tonic::Code::*;
+
+    if let Some(retry_delay) = status
+        .get_details_retry_info()
+        .and_then(|retry_info| retry_info.retry_delay)
+    {
+        let seconds_millis = (retry_delay.seconds.max(0) as u64).saturating_mul(1000);
+        let nanos_millis = (retry_delay.nanos.max(0) as u64) / 1_000_000;
+        let millis = seconds_millis.saturating_add(nanos_millis);
+        return RetryErrorType::RetryAfter(Duration::from_millis(millis));
+    }
+
+    match status.code() {
+        Cancelled | DeadlineExceeded | Aborted | OutOfRange | DataLoss | Unavailable => {
+            RetryErrorType::Retryable
+        }
+        _ => RetryErrorType::NonRetryable,
+    }
+}
+
+fn backoff_delay(policy: &RetryPolicy, attempt: u32) -> Duration {
+    let exponential = policy
+        .initial_delay_ms
+        .saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX))
+        .min(policy.max_delay_ms);
+    let jitter = if policy.jitter_ms == 0 {
+        0
+    } else {
+        rand::rng().random_range(0..=policy.jitter_ms)
+    };
+    Duration::from_millis(exponential.saturating_add(jitter))
+}
+
+/// The number of tokens a retry attempt withdraws from the exporter's retry
+/// token bucket. Timeout-class errors are cheaper to retry than other
+/// failures, since a slow-but-alive collector is less concerning than one
+/// that's erroring outright.
+fn retry_token_cost(status: &Status) -> usize {
+    if status.code() == tonic::Code::DeadlineExceeded {
+        5
+    } else {
+        10
+    }
+}
+
+/// A successful request refills the retry token bucket by this amount,
+/// capped at the bucket's configured capacity.
+const RETRY_TOKEN_REFILL: usize = 1;
+
+fn try_acquire_retry_tokens(bucket: &AtomicUsize, cost: usize) -> bool {
+    let mut current = bucket.load(Ordering::Acquire);
+    loop {
+        if current < cost {
+            return false;
+        }
+
+        match bucket.compare_exchange_weak(
+            current,
+            current - cost,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => return true,
+            Err(observed) => current = observed,
+        }
+    }
+}
+
+fn refill_retry_tokens(bucket: &AtomicUsize, capacity: usize) {
+    let mut current = bucket.load(Ordering::Acquire);
+    loop {
+        let next = current.saturating_add(RETRY_TOKEN_REFILL).min(capacity);
+        match bucket.compare_exchange_weak(current, next, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => return,
+            Err(observed) => current = observed,
+        }
+    }
+}
+
+/// Sends `request` to the collector, retrying according to `retry_policy`
+/// and `classifier` when the request fails with a retryable error.
+///
+/// `retry_tokens` is the exporter's shared retry token bucket (see
+/// [`RetryPolicy::retry_tokens`]); when it's exhausted, retrying stops
+/// immediately and the last error is returned even if the policy would
+/// otherwise allow another attempt.
+///
+/// The decoded response is returned as-is, including any `partial_success`
+/// it carries, so the caller can decide how to surface a partial rejection.
+pub async fn export_with_retry(
+    client: &mut LogsServiceClient<Channel>,
+    retry_policy: &RetryPolicy,
+    classifier: &Arc<dyn RetryClassifier>,
+    retry_tokens: &AtomicUsize,
+    request: &ExportLogsServiceRequest,
+) -> Result<ExportLogsServiceResponse, Error> {
+    let mut attempt = 0;
+
+    loop {
+        match client.export(request.clone()).await {
+            Ok(response) => {
+                refill_retry_tokens(retry_tokens, retry_policy.retry_tokens);
+                return Ok(response.into_inner());
+            }
+            Err(status) => {
+                if attempt >= retry_policy.max_retries {
+                    return Err(Error::Status(status));
+                }
+
+                let delay = match classifier.classify(&status) {
+                    RetryErrorType::Retryable => backoff_delay(retry_policy, attempt),
+                    RetryErrorType::RetryAfter(server_delay) => {
+                        server_delay.min(Duration::from_millis(retry_policy.max_delay_ms))
+                    }
+                    RetryErrorType::NonRetryable => return Err(Error::Status(status)),
+                };
+
+                if !try_acquire_retry_tokens(retry_tokens, retry_token_cost(&status)) {
+                    return Err(Error::Status(status));
+                }
+
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic_types::ErrorDetails;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct FixedClassifier(RetryErrorType);
+
+    impl RetryClassifier for FixedClassifier {
+        fn classify(&self, _status: &Status) -> RetryErrorType {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_chain_retry_classifier_first_non_default_wins() {
+        let chain = ChainRetryClassifier::new(vec![
+            Arc::new(FixedClassifier(RetryErrorType::NonRetryable)),
+            Arc::new(FixedClassifier(RetryErrorType::Retryable)),
+            Arc::new(FixedClassifier(RetryErrorType::NonRetryable)),
+        ]);
+
+        let status = Status::internal("boom");
+        assert_eq!(chain.classify(&status), RetryErrorType::Retryable);
+    }
+
+    #[test]
+    fn test_chain_retry_classifier_falls_through_to_default() {
+        let chain = ChainRetryClassifier::new(vec![
+            Arc::new(FixedClassifier(RetryErrorType::NonRetryable)),
+            Arc::new(FixedClassifier(RetryErrorType::NonRetryable)),
+        ]);
+
+        let status = Status::internal("boom");
+        assert_eq!(chain.classify(&status), RetryErrorType::NonRetryable);
+    }
+
+    #[test]
+    fn test_classify_resource_exhausted_with_retry_info() {
+        let details = ErrorDetails::with_retry_info(Some(Duration::from_millis(1500)));
+        let status =
+            Status::with_error_details(tonic::Code::ResourceExhausted, "Too many requests", details);
+
+        let classification = classify_tonic_status(&status);
+        assert_eq!(
+            classification,
+            RetryErrorType::RetryAfter(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_try_acquire_retry_tokens_drains_then_short_circuits() {
+        let bucket = AtomicUsize::new(10);
+        let status = Status::unavailable("unavailable");
+        let cost = retry_token_cost(&status);
+        assert_eq!(cost, 10);
+
+        // The bucket starts with exactly enough tokens for one retry.
+        assert!(try_acquire_retry_tokens(&bucket, cost));
+        assert_eq!(bucket.load(Ordering::Relaxed), 0);
+
+        // It's now empty, so the next retry attempt must short-circuit
+        // instead of being allowed to sleep and retry again.
+        assert!(!try_acquire_retry_tokens(&bucket, cost));
+        assert_eq!(bucket.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_refill_retry_tokens_caps_at_capacity() {
+        let bucket = AtomicUsize::new(499);
+        refill_retry_tokens(&bucket, 500);
+        assert_eq!(bucket.load(Ordering::Relaxed), 500);
+
+        // Already at capacity: refilling must not exceed it.
+        refill_retry_tokens(&bucket, 500);
+        assert_eq!(bucket.load(Ordering::Relaxed), 500);
+    }
+}